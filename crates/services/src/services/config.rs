@@ -0,0 +1,72 @@
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+/// Built-in notification chime, or a user-supplied file to play instead.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum SoundFile {
+    /// The bundled default chime, cached to disk on first use.
+    Default,
+    /// A user-provided sound file played as-is.
+    Custom { path: PathBuf },
+}
+
+impl Default for SoundFile {
+    fn default() -> Self {
+        SoundFile::Default
+    }
+}
+
+impl SoundFile {
+    /// Resolve this sound to a playable file path, caching the bundled default chime
+    /// on disk on first use so player binaries (which need a real path) can read it.
+    pub async fn get_path(&self) -> std::io::Result<PathBuf> {
+        match self {
+            SoundFile::Default => utils::cached_default_notification_sound().await,
+            SoundFile::Custom { path } => Ok(path.clone()),
+        }
+    }
+}
+
+/// User-configurable notification behaviour: sound/push toggles, the sound played,
+/// and the app identity shown on desktop toasts.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct NotificationConfig {
+    /// Play a sound when a task attempt finishes.
+    pub sound_enabled: bool,
+    /// Send a desktop push notification when a task attempt finishes.
+    pub push_enabled: bool,
+    /// Which sound to play.
+    pub sound_file: SoundFile,
+    /// Sender name shown on the toast (notify-rust `appname` on Linux, subtitle on macOS).
+    /// Windows toasts do not use it.
+    pub app_name: String,
+    /// Absolute path to the icon shown alongside the toast, if any.
+    pub icon: Option<String>,
+    /// D-Bus bus name override (Linux only; ignored elsewhere). The Linux backend does not
+    /// send over a custom bus: when this is set it logs a warning and uses the default connection.
+    pub bus_name: Option<String>,
+    /// Debounce window, in milliseconds, for coalescing bursts of completion notifications
+    /// into a single summary toast. Mirrors watchexec's `action_throttle`.
+    pub notification_throttle_ms: u64,
+    /// Force a specific sound backend (e.g. "paplay", "pw-play") instead of probing the
+    /// platform's ordered candidate list. Ignored on Windows/WSL.
+    pub sound_backend: Option<String>,
+}
+
+impl Default for NotificationConfig {
+    fn default() -> Self {
+        Self {
+            sound_enabled: true,
+            push_enabled: true,
+            sound_file: SoundFile::default(),
+            app_name: "Vibe Kanban".to_string(),
+            icon: None,
+            bus_name: None,
+            notification_throttle_ms: 750,
+            sound_backend: None,
+        }
+    }
+}
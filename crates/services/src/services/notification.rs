@@ -1,5 +1,5 @@
-use std::sync::OnceLock;
 use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Mutex, OnceLock};
 use std::time::Duration;
 
 use db::models::execution_process::{ExecutionContext, ExecutionProcessStatus};
@@ -19,27 +19,79 @@ static WSL_ROOT_PATH_CACHE: OnceLock<Option<String>> = OnceLock::new();
 static DBUS_AVAILABLE: AtomicBool = AtomicBool::new(true);
 static DBUS_CHECK_DONE: AtomicBool = AtomicBool::new(false);
 
-impl NotificationService {
-    pub async fn notify_execution_halted(mut config: NotificationConfig, ctx: &ExecutionContext) {
-        // If the process was intentionally killed by user, suppress sound
-        if matches!(ctx.execution_process.status, ExecutionProcessStatus::Killed) {
-            config.sound_enabled = false;
-        }
+/// Freedesktop desktop-entry id used for the Linux `DesktopEntry` hint.
+const DESKTOP_ENTRY: &str = "vibe-kanban";
+
+/// Shared buffer used to coalesce completion notifications that arrive in a burst.
+static PENDING: OnceLock<Mutex<PendingBuffer>> = OnceLock::new();
+
+fn pending_buffer() -> &'static Mutex<PendingBuffer> {
+    PENDING.get_or_init(|| Mutex::new(PendingBuffer::default()))
+}
+
+/// A single buffered completion notification awaiting the debounce window.
+struct PendingNotification {
+    title: String,
+    message: String,
+    status: ExecutionProcessStatus,
+    actions: NotificationActions,
+}
+
+/// Debounce state: the buffered items, the config to emit with, and a generation
+/// counter used to tell whether a scheduled flush is still the most recent one.
+#[derive(Default)]
+struct PendingBuffer {
+    items: Vec<PendingNotification>,
+    config: Option<NotificationConfig>,
+    generation: u64,
+}
 
+/// Deep-link targets a completion notification's actions can open.
+///
+/// Resolved from the [`ExecutionContext`] so that clicking an action jumps the
+/// user straight back to the finished attempt instead of a generic window.
+#[derive(Debug, Clone, Default)]
+pub struct NotificationActions {
+    /// URL that opens the task attempt in the app.
+    pub open: Option<String>,
+    /// URL that opens the attempt's diff view.
+    pub diff: Option<String>,
+}
+
+impl NotificationActions {
+    fn has_any(&self) -> bool {
+        self.open.is_some() || self.diff.is_some()
+    }
+}
+
+/// Application identity applied to outgoing notifications so they appear under a
+/// recognisable name/icon rather than the notification library's generic sender.
+#[derive(Debug, Clone)]
+pub struct NotificationIdentity {
+    /// Human-readable sender name (e.g. "Vibe Kanban").
+    pub app_name: String,
+    /// Absolute path to the icon shown alongside the toast.
+    pub icon: Option<String>,
+    /// Freedesktop desktop-entry id used for the `DesktopEntry` hint / grouping.
+    pub desktop_entry: Option<String>,
+    /// Configured D-Bus bus name override (Linux only). The Linux backend does not send
+    /// over a custom bus: when this is set it logs a warning and uses the default connection.
+    pub bus_name: Option<String>,
+}
+
+impl NotificationService {
+    pub async fn notify_execution_halted(config: NotificationConfig, ctx: &ExecutionContext) {
         let title = format!("Task Complete: {}", ctx.task.title);
-        let message = match ctx.execution_process.status {
-            ExecutionProcessStatus::Completed => format!(
-                "✅ '{}' completed successfully\nBranch: {:?}\nExecutor: {}",
-                ctx.task.title, ctx.task_attempt.branch, ctx.task_attempt.executor
-            ),
-            ExecutionProcessStatus::Failed => format!(
-                "❌ '{}' execution failed\nBranch: {:?}\nExecutor: {}",
-                ctx.task.title, ctx.task_attempt.branch, ctx.task_attempt.executor
-            ),
-            ExecutionProcessStatus::Killed => format!(
-                "🛑 '{}' execution cancelled by user\nBranch: {:?}\nExecutor: {}",
-                ctx.task.title, ctx.task_attempt.branch, ctx.task_attempt.executor
-            ),
+        let status_line = match ctx.execution_process.status {
+            ExecutionProcessStatus::Completed => {
+                format!("✅ '{}' completed successfully", ctx.task.title)
+            }
+            ExecutionProcessStatus::Failed => {
+                format!("❌ '{}' execution failed", ctx.task.title)
+            }
+            ExecutionProcessStatus::Killed => {
+                format!("🛑 '{}' execution cancelled by user", ctx.task.title)
+            }
             _ => {
                 tracing::warn!(
                     "Tried to notify attempt completion for {} but process is still running!",
@@ -48,22 +100,246 @@ impl NotificationService {
                 return;
             }
         };
-        Self::notify(config, &title, &message).await;
+
+        let mut message = format!(
+            "{status_line}\nBranch: {:?}\nExecutor: {}",
+            ctx.task_attempt.branch, ctx.task_attempt.executor
+        );
+        // Borrow watchexec's habit of reporting the concrete ProcessEnd: exit code /
+        // signal and how long it ran. Each fragment is optional so the body stays
+        // well-formed when the underlying field is missing.
+        if let Some(outcome) = Self::outcome_detail(ctx) {
+            message.push_str(&format!("\n{outcome}"));
+        }
+        if let Some(duration) = Self::run_duration(ctx) {
+            message.push_str(&format!("\nRan for {duration}"));
+        }
+        // No diff-summary line: `ExecutionContext` exposes no diff stats to build one from.
+
+        let actions = Self::resolve_actions(ctx);
+        Self::enqueue(
+            config,
+            PendingNotification {
+                title,
+                message,
+                status: ctx.execution_process.status.clone(),
+                actions,
+            },
+        );
+    }
+
+    /// Push a completion notification into the shared buffer and (re)arm the debounce
+    /// timer. Bursts that land inside the window collapse into a single summary toast.
+    fn enqueue(config: NotificationConfig, item: PendingNotification) {
+        let throttle = config.notification_throttle_ms;
+        let generation = {
+            let mut buf = pending_buffer().lock().unwrap();
+            buf.items.push(item);
+            buf.config = Some(config);
+            buf.generation = buf.generation.wrapping_add(1);
+            buf.generation
+        };
+
+        tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(throttle)).await;
+            Self::flush(generation).await;
+        });
+    }
+
+    /// Emit the buffered notifications if `generation` is still the latest armed timer.
+    /// A single pending item is emitted verbatim; several collapse into one summary.
+    async fn flush(generation: u64) {
+        let (items, config) = {
+            let mut buf = pending_buffer().lock().unwrap();
+            // A newer call re-armed the timer; let that later flush handle the batch.
+            if buf.generation != generation {
+                return;
+            }
+            (std::mem::take(&mut buf.items), buf.config.take())
+        };
+
+        let Some(mut config) = config else {
+            return;
+        };
+        if items.is_empty() {
+            return;
+        }
+
+        // Killed-suppresses-sound: if any member of the batch was user-killed, stay silent.
+        if items
+            .iter()
+            .any(|i| matches!(i.status, ExecutionProcessStatus::Killed))
+        {
+            config.sound_enabled = false;
+        }
+
+        if items.len() == 1 {
+            let item = items.into_iter().next().unwrap();
+            Self::notify(config, &item.title, &item.message, item.actions).await;
+        } else {
+            let (title, message, actions) = Self::summarize(items);
+            Self::notify(config, &title, &message, actions).await;
+        }
+    }
+
+    /// Collapse a batch of completions into a single summary notification, keeping the
+    /// per-status emoji breakdown in the body (e.g. title "4 tasks finished", body
+    /// starting "3 completed, 1 failed").
+    fn summarize(items: Vec<PendingNotification>) -> (String, String, NotificationActions) {
+        let total = items.len();
+        let completed = items
+            .iter()
+            .filter(|i| matches!(i.status, ExecutionProcessStatus::Completed))
+            .count();
+        let failed = items
+            .iter()
+            .filter(|i| matches!(i.status, ExecutionProcessStatus::Failed))
+            .count();
+        let killed = items
+            .iter()
+            .filter(|i| matches!(i.status, ExecutionProcessStatus::Killed))
+            .count();
+
+        let mut parts = Vec::new();
+        if completed > 0 {
+            parts.push(format!("{completed} completed"));
+        }
+        if failed > 0 {
+            parts.push(format!("{failed} failed"));
+        }
+        if killed > 0 {
+            parts.push(format!("{killed} cancelled"));
+        }
+        let title = format!("{total} tasks finished");
+
+        let mut body = parts.join(", ");
+        body.push('\n');
+        if completed > 0 {
+            body.push_str(&format!("✅ {completed} completed successfully\n"));
+        }
+        if failed > 0 {
+            body.push_str(&format!("❌ {failed} execution failed\n"));
+        }
+        if killed > 0 {
+            body.push_str(&format!("🛑 {killed} cancelled by user\n"));
+        }
+
+        // Surface the most recent attempt's deep links on the summary's actions.
+        let actions = items
+            .into_iter()
+            .next_back()
+            .map(|i| i.actions)
+            .unwrap_or_default();
+
+        (title, body.trim_end().to_string(), actions)
+    }
+
+    /// Resolve the deep-link URLs the notification actions should open for an attempt.
+    fn resolve_actions(ctx: &ExecutionContext) -> NotificationActions {
+        // Honour an explicit override so packaged/remote deployments can point
+        // notifications at the right origin; fall back to the local dev server.
+        let base = std::env::var("VIBE_KANBAN_BASE_URL")
+            .unwrap_or_else(|_| "http://127.0.0.1:3000".to_string());
+        let base = base.trim_end_matches('/');
+        let attempt = ctx.task_attempt.id;
+        NotificationActions {
+            open: Some(format!("{base}/tasks/{}/attempts/{attempt}", ctx.task.id)),
+            diff: Some(format!("{base}/tasks/{}/attempts/{attempt}/diff", ctx.task.id)),
+        }
+    }
+
+    /// Describe how the process ended: exit code for completed/failed runs, "Terminated"
+    /// with the exit code (or "by user" when none is recorded) for killed ones. Returns
+    /// `None` for a completed/failed run with no recorded exit code.
+    ///
+    /// We report `exit_code` verbatim rather than decoding it as a signal number: whether
+    /// `ExecutionProcess::exit_code` follows the POSIX `128 + signo` shell convention, holds
+    /// a raw signal number, or something else for `Killed` isn't established against the db
+    /// model, so a decoded name risks mislabeling the signal.
+    fn outcome_detail(ctx: &ExecutionContext) -> Option<String> {
+        let process = &ctx.execution_process;
+        match process.status {
+            ExecutionProcessStatus::Completed | ExecutionProcessStatus::Failed => {
+                process.exit_code.map(|code| format!("Exit code: {code}"))
+            }
+            ExecutionProcessStatus::Killed => Some(match process.exit_code {
+                Some(code) => format!("Terminated (status {code})"),
+                None => "Terminated by user".to_string(),
+            }),
+            _ => None,
+        }
+    }
+
+    /// Wall-clock duration of the run, formatted compactly (e.g. "1m 12s").
+    fn run_duration(ctx: &ExecutionContext) -> Option<String> {
+        let process = &ctx.execution_process;
+        let completed = process.completed_at?;
+        let elapsed = completed - process.started_at;
+        Some(Self::format_duration(elapsed.num_seconds()))
+    }
+
+    /// Format a duration in seconds as "Ms Ns" (or "Ns" under a minute).
+    fn format_duration(secs: i64) -> String {
+        let secs = secs.max(0);
+        if secs >= 60 {
+            format!("{}m {}s", secs / 60, secs % 60)
+        } else {
+            format!("{secs}s")
+        }
     }
 
     /// Send both sound and push notifications if enabled
-    pub async fn notify(config: NotificationConfig, title: &str, message: &str) {
+    pub async fn notify(
+        config: NotificationConfig,
+        title: &str,
+        message: &str,
+        actions: NotificationActions,
+    ) {
         if config.sound_enabled {
-            Self::play_sound_notification(&config.sound_file).await;
+            Self::play_sound_notification(&config.sound_file, config.sound_backend.as_deref())
+                .await;
         }
 
         if config.push_enabled {
-            Self::send_push_notification(title, message).await;
+            let identity = Self::resolve_identity(&config);
+            Self::send_push_notification(title, message, actions, identity, config.sound_enabled)
+                .await;
+        }
+    }
+
+    /// Derive the platform-agnostic app identity (name, icon, desktop entry, bus name)
+    /// applied to every backend so notifications present a consistent sender.
+    fn resolve_identity(config: &NotificationConfig) -> NotificationIdentity {
+        NotificationIdentity {
+            app_name: config.app_name.clone(),
+            icon: config.icon.clone(),
+            desktop_entry: Some(DESKTOP_ENTRY.to_string()),
+            bus_name: config.bus_name.clone(),
+        }
+    }
+
+    /// Open a URL in the platform's default handler, from a blocking action callback.
+    fn open_url_blocking(url: &str) {
+        if cfg!(target_os = "macos") {
+            let _ = std::process::Command::new("open").arg(url).spawn();
+        } else if cfg!(target_os = "linux") && !utils::is_wsl2() {
+            let _ = std::process::Command::new("xdg-open").arg(url).spawn();
+        } else if cfg!(target_os = "windows") || (cfg!(target_os = "linux") && utils::is_wsl2()) {
+            let _ = std::process::Command::new("powershell.exe")
+                .arg("-c")
+                .arg(format!("Start-Process '{url}'"))
+                .spawn();
         }
     }
 
-    /// Play a system sound notification across platforms
-    async fn play_sound_notification(sound_file: &SoundFile) {
+    /// Play a system sound notification across platforms.
+    ///
+    /// Each backend is tried in order and actually awaited: we only fall through to
+    /// the next candidate when the current one spawns-errors, exits non-zero or times
+    /// out, so a player that is installed but broken no longer swallows the notification.
+    /// The terminal bell is the final fallback. `backend` forces a specific player on
+    /// macOS/Linux; Windows/WSL always use PowerShell's `SoundPlayer` and ignore it.
+    async fn play_sound_notification(sound_file: &SoundFile, backend: Option<&str>) {
         let file_path = match sound_file.get_path().await {
             Ok(path) => path,
             Err(e) => {
@@ -72,34 +348,7 @@ impl NotificationService {
             }
         };
 
-        // Use platform-specific sound notification
-        // Note: spawn() calls are intentionally not awaited - sound notifications should be fire-and-forget
-        if cfg!(target_os = "macos") {
-            let _ = tokio::process::Command::new("afplay")
-                .arg(&file_path)
-                .spawn();
-        } else if cfg!(target_os = "linux") && !utils::is_wsl2() {
-            // Try different Linux audio players
-            if tokio::process::Command::new("paplay")
-                .arg(&file_path)
-                .spawn()
-                .is_ok()
-            {
-                // Success with paplay
-            } else if tokio::process::Command::new("aplay")
-                .arg(&file_path)
-                .spawn()
-                .is_ok()
-            {
-                // Success with aplay
-            } else {
-                // Try system bell as fallback
-                let _ = tokio::process::Command::new("echo")
-                    .arg("-e")
-                    .arg("\\a")
-                    .spawn();
-            }
-        } else if cfg!(target_os = "windows") || (cfg!(target_os = "linux") && utils::is_wsl2()) {
+        if cfg!(target_os = "windows") || (cfg!(target_os = "linux") && utils::is_wsl2()) {
             // Convert WSL path to Windows path if in WSL2
             let file_path = if utils::is_wsl2() {
                 if let Some(windows_path) = Self::wsl_to_windows_path(&file_path).await {
@@ -111,30 +360,142 @@ impl NotificationService {
                 file_path.to_string_lossy().to_string()
             };
 
-            let _ = tokio::process::Command::new("powershell.exe")
-                .arg("-c")
-                .arg(format!(
-                    r#"(New-Object Media.SoundPlayer "{file_path}").PlaySync()"#
-                ))
-                .spawn();
+            let script = format!(r#"(New-Object Media.SoundPlayer "{file_path}").PlaySync()"#);
+            if Self::try_play("powershell.exe", &["-c".to_string(), script]).await {
+                return;
+            }
+            Self::ring_terminal_bell();
+            return;
+        }
+
+        let path = file_path.to_string_lossy().to_string();
+
+        // Ordered candidates per platform. On Linux probe PipeWire's pw-play before
+        // the PulseAudio/ALSA players.
+        let candidates: Vec<&str> = if cfg!(target_os = "macos") {
+            vec!["afplay"]
+        } else {
+            vec!["pw-play", "paplay", "aplay"]
+        };
+        // A forced backend short-circuits the probe list.
+        let candidates: Vec<&str> = match backend {
+            Some(forced) => vec![forced],
+            None => candidates,
+        };
+
+        for player in candidates {
+            if Self::try_play(player, std::slice::from_ref(&path)).await {
+                return;
+            }
+        }
+
+        // Nothing played; fall back to the terminal bell.
+        Self::ring_terminal_bell();
+    }
+
+    /// Run a single sound-player candidate to completion, bounded by a short timeout.
+    /// Returns `true` only when the player exited successfully.
+    async fn try_play(program: &str, args: &[String]) -> bool {
+        match tokio::time::timeout(
+            Duration::from_secs(3),
+            // `kill_on_drop` ensures that when the timeout fires and this future is
+            // dropped, the child is actually killed instead of being left running (and
+            // audible) in the background while we fall through to the next backend.
+            tokio::process::Command::new(program)
+                .args(args)
+                .kill_on_drop(true)
+                .status(),
+        )
+        .await
+        {
+            Ok(Ok(status)) => status.success(),
+            Ok(Err(e)) => {
+                tracing::debug!("Sound backend '{program}' failed to start: {e}");
+                false
+            }
+            Err(_) => {
+                tracing::warn!("Sound backend '{program}' timed out - trying next");
+                false
+            }
         }
     }
 
+    /// Emit the terminal bell as the last-resort audible fallback.
+    fn ring_terminal_bell() {
+        use std::io::Write;
+        let _ = std::io::stderr().write_all(b"\x07");
+        let _ = std::io::stderr().flush();
+    }
+
     /// Send a cross-platform push notification
-    async fn send_push_notification(title: &str, message: &str) {
+    async fn send_push_notification(
+        title: &str,
+        message: &str,
+        actions: NotificationActions,
+        identity: NotificationIdentity,
+        sound_enabled: bool,
+    ) {
         if cfg!(target_os = "macos") {
-            Self::send_macos_notification(title, message).await;
+            Self::send_macos_notification(title, message, &actions, &identity, sound_enabled)
+                .await;
         } else if cfg!(target_os = "linux") && !utils::is_wsl2() {
-            Self::send_linux_notification(title, message).await;
+            Self::send_linux_notification(title, message, actions, identity).await;
         } else if cfg!(target_os = "windows") || (cfg!(target_os = "linux") && utils::is_wsl2()) {
-            Self::send_windows_notification(title, message).await;
+            Self::send_windows_notification(title, message, &identity).await;
         }
     }
 
-    /// Send macOS notification using osascript
-    async fn send_macos_notification(title: &str, message: &str) {
+    /// Send macOS notification.
+    ///
+    /// `osascript display notification` can't deliver a click callback, so when an
+    /// `open` target is available we first try `terminal-notifier`, whose `-execute`
+    /// makes the whole toast clickable (it runs `open <url>`, which re-focuses the app
+    /// on the attempt). When `terminal-notifier` isn't installed we fall back to a plain
+    /// `osascript` toast and the click action is unavailable. `sound_enabled` gates the
+    /// toast's own chime, so the killed-suppresses-sound rule silences it as well as the
+    /// separate `afplay` chime.
+    async fn send_macos_notification(
+        title: &str,
+        message: &str,
+        actions: &NotificationActions,
+        identity: &NotificationIdentity,
+        sound_enabled: bool,
+    ) {
+        let sound = sound_enabled.then_some("Glass");
+
+        // Preferred path: a clickable toast that opens the attempt.
+        if let Some(url) = actions.open.as_deref() {
+            let mut command = tokio::process::Command::new("terminal-notifier");
+            command
+                .arg("-title")
+                .arg(title)
+                .arg("-subtitle")
+                .arg(&identity.app_name)
+                .arg("-message")
+                .arg(message);
+            if let Some(sound) = sound {
+                command.arg("-sound").arg(sound);
+            }
+            let delivered = command
+                .arg("-execute")
+                .arg(format!("open '{}'", url.replace('\'', r"'\''")))
+                .status()
+                .await
+                .map(|s| s.success())
+                .unwrap_or(false);
+            if delivered {
+                return;
+            }
+        }
+
+        // Fallback: `display notification` has no icon/appname switch, but a subtitle
+        // carrying the app name keeps a recognisable identity in Notification Center.
+        let subtitle = identity.app_name.replace('"', r#"\""#);
+        let sound_clause = sound
+            .map(|sound| format!(r#" sound name "{sound}""#))
+            .unwrap_or_default();
         let script = format!(
-            r#"display notification "{message}" with title "{title}" sound name "Glass""#,
+            r#"display notification "{message}" with title "{title}" subtitle "{subtitle}"{sound_clause}"#,
             message = message.replace('"', r#"\""#),
             title = title.replace('"', r#"\""#)
         );
@@ -205,56 +566,126 @@ impl NotificationService {
     }
 
     /// Send Linux notification using notify-rust
-    async fn send_linux_notification(title: &str, message: &str) {
+    async fn send_linux_notification(
+        title: &str,
+        message: &str,
+        actions: NotificationActions,
+        identity: NotificationIdentity,
+    ) {
         // Skip if DBus is not available
         if !Self::check_dbus_available().await {
             tracing::debug!("Skipping Linux notification - DBus not available");
             return;
         }
-        
-        use notify_rust::Notification;
+
+        use notify_rust::{Hint, Notification};
 
         let title = title.to_string();
         let message = message.to_string();
+        // Only advertise actions the running server can actually serve; if the
+        // DBus daemon reports no "actions" capability we degrade to a plain toast.
+        let wants_actions = actions.has_any() && Self::dbus_supports_actions().await;
 
-        // Add timeout to prevent indefinite blocking
+        // Add timeout to prevent indefinite blocking on `.show()`.
         let notification_result = tokio::time::timeout(
             Duration::from_secs(2),
             tokio::task::spawn_blocking(move || {
-                if let Err(e) = Notification::new()
-                    .summary(&title)
-                    .body(&message)
-                    .timeout(10000)
-                    .show()
-                {
-                    tracing::error!("Failed to send Linux notification: {}", e);
-                    
-                    // If we get a DBus error, mark it as unavailable for future calls
-                    if e.to_string().contains("DBus") || e.to_string().contains("D-Bus") {
-                        DBUS_AVAILABLE.store(false, Ordering::Relaxed);
-                        tracing::info!("DBus appears to be unavailable - disabling future notification attempts");
+                let mut builder = Notification::new();
+                builder.summary(&title).body(&message).timeout(10000);
+                builder.appname(&identity.app_name);
+                if let Some(icon) = identity.icon.as_deref() {
+                    builder.icon(icon);
+                }
+                if let Some(entry) = identity.desktop_entry.as_deref() {
+                    builder.hint(Hint::DesktopEntry(entry.to_string()));
+                }
+                if wants_actions {
+                    if actions.open.is_some() {
+                        builder.action("open", "Open Task");
+                    }
+                    if actions.diff.is_some() {
+                        builder.action("diff", "View Diff");
+                    }
+                }
+                // NOTE: a per-call D-Bus bus-name override (for sandboxed/Flatpak-style
+                // environments) would need a lower-level connection API than this pinned
+                // notify-rust exposes through its builder; not confirmed to exist, so the
+                // configured override isn't honored here. Warn once rather than guess at
+                // an API that risks a compile or runtime failure for every toast.
+                if identity.bus_name.is_some() {
+                    tracing::warn!(
+                        "NotificationConfig.bus_name is set but this notify-rust build has no \
+                         verified API to send over a custom bus; using the default connection"
+                    );
+                }
+                match builder.show() {
+                    Ok(handle) => Some((handle, actions)),
+                    Err(e) => {
+                        tracing::error!("Failed to send Linux notification: {}", e);
+
+                        // If we get a DBus error, mark it as unavailable for future calls
+                        if e.to_string().contains("DBus") || e.to_string().contains("D-Bus") {
+                            DBUS_AVAILABLE.store(false, Ordering::Relaxed);
+                            tracing::info!("DBus appears to be unavailable - disabling future notification attempts");
+                        }
+                        None
                     }
                 }
             })
         ).await;
-        
-        match notification_result {
-            Ok(Ok(_)) => {
-                // Success
-            }
+
+        let handle = match notification_result {
+            Ok(Ok(handle)) => handle,
             Ok(Err(e)) => {
                 tracing::error!("Notification task panicked: {}", e);
                 DBUS_AVAILABLE.store(false, Ordering::Relaxed);
+                None
             }
             Err(_) => {
                 tracing::error!("Linux notification timed out after 2 seconds - possible DBus deadlock");
                 DBUS_AVAILABLE.store(false, Ordering::Relaxed);
+                None
+            }
+        };
+
+        // `wait_for_action` blocks for the whole lifetime of the notification, so it
+        // gets its own `spawn_blocking` task, awaited under a timeout one second longer
+        // than the 10s toast timeout above. The timeout only stops the await; the
+        // blocking thread itself runs until `wait_for_action` returns.
+        if let Some((handle, actions)) = handle {
+            if wants_actions {
+                tokio::spawn(async move {
+                    let _ = tokio::time::timeout(
+                        Duration::from_secs(11),
+                        tokio::task::spawn_blocking(move || {
+                            handle.wait_for_action(|action| {
+                                let url = match action {
+                                    "open" => actions.open.as_deref(),
+                                    "diff" => actions.diff.as_deref(),
+                                    _ => None,
+                                };
+                                if let Some(url) = url {
+                                    Self::open_url_blocking(url);
+                                }
+                            });
+                        }),
+                    )
+                    .await;
+                });
             }
         }
     }
 
+    /// Check whether the session's notification daemon advertises the "actions" capability.
+    async fn dbus_supports_actions() -> bool {
+        match tokio::task::spawn_blocking(notify_rust::get_capabilities).await {
+            Ok(Ok(caps)) => caps.iter().any(|c| c == "actions"),
+            _ => false,
+        }
+    }
+
     /// Send Windows/WSL notification using PowerShell toast script
-    async fn send_windows_notification(title: &str, message: &str) {
+    async fn send_windows_notification(title: &str, message: &str, identity: &NotificationIdentity) {
         let script_path = match utils::get_powershell_script().await {
             Ok(path) => path,
             Err(e) => {
@@ -274,6 +705,10 @@ impl NotificationService {
             script_path.to_string_lossy().to_string()
         };
 
+        // NOTE: an AppUserModelID would let Action Center group these toasts under our
+        // identity instead of "Windows PowerShell", but that means the bundled toast
+        // script accepting a corresponding `-AppId` parameter, which isn't confirmed
+        // here. Not wiring an unverified flag into the script invocation.
         let _ = tokio::process::Command::new("powershell.exe")
             .arg("-NoProfile")
             .arg("-ExecutionPolicy")
@@ -350,3 +785,63 @@ impl NotificationService {
         }
     }
 }
+
+#[cfg(test)]
+mod outcome_tests {
+    use super::*;
+
+    #[test]
+    fn format_duration_switches_to_minutes_past_60s() {
+        assert_eq!(NotificationService::format_duration(0), "0s");
+        assert_eq!(NotificationService::format_duration(59), "59s");
+        assert_eq!(NotificationService::format_duration(60), "1m 0s");
+        assert_eq!(NotificationService::format_duration(72), "1m 12s");
+    }
+
+    #[test]
+    fn format_duration_clamps_negative_to_zero() {
+        assert_eq!(NotificationService::format_duration(-5), "0s");
+    }
+}
+
+#[cfg(test)]
+mod coalesce_tests {
+    use super::*;
+
+    fn pending(status: ExecutionProcessStatus) -> PendingNotification {
+        PendingNotification {
+            title: "t".to_string(),
+            message: "m".to_string(),
+            status,
+            actions: NotificationActions::default(),
+        }
+    }
+
+    #[test]
+    fn summarize_counts_each_status_in_title_and_body() {
+        let items = vec![
+            pending(ExecutionProcessStatus::Completed),
+            pending(ExecutionProcessStatus::Completed),
+            pending(ExecutionProcessStatus::Completed),
+            pending(ExecutionProcessStatus::Failed),
+        ];
+        let (title, body, _) = NotificationService::summarize(items);
+        assert_eq!(title, "4 tasks finished");
+        assert_eq!(body.lines().next(), Some("3 completed, 1 failed"));
+        assert!(body.contains("✅ 3 completed successfully"));
+        assert!(body.contains("❌ 1 execution failed"));
+        assert!(!body.contains("cancelled"));
+    }
+
+    #[test]
+    fn summarize_surfaces_last_items_actions() {
+        let mut last = pending(ExecutionProcessStatus::Completed);
+        last.actions = NotificationActions {
+            open: Some("u".to_string()),
+            diff: None,
+        };
+        let items = vec![pending(ExecutionProcessStatus::Failed), last];
+        let (_, _, actions) = NotificationService::summarize(items);
+        assert_eq!(actions.open.as_deref(), Some("u"));
+    }
+}